@@ -0,0 +1,92 @@
+use time::Date;
+use time::Duration;
+use time::Weekday;
+
+use crate::regions::Region;
+
+/// A trait that provides business-day arithmetic (payroll, SLA, and
+/// settlement-date math) on top of a region's holiday calendar.
+///
+/// A business day is a weekday (Monday through Friday) that is not a
+/// holiday in the region, as determined by [`Region::is_holiday`].
+pub trait BusinessDay {
+    /// Checks whether the given date is a business day, i.e. a weekday
+    /// that is not a holiday in the region.
+    fn is_business_day(&self, date: Date) -> bool;
+
+    /// Rolls `date` to the nearest business day.
+    ///
+    /// If `date` is already a business day, it is returned unchanged.
+    /// Otherwise, the date is moved forward one day at a time if
+    /// `adjust_next` is `true`, or backward one day at a time if
+    /// `adjust_next` is `false`, until a business day is found.
+    fn next_business_day(&self, date: Date, adjust_next: bool) -> Date;
+
+    /// Steps `n` business days forward from `date`, or backward if `n`
+    /// is negative, skipping weekends and holidays.
+    ///
+    /// `date` itself is not counted; stepping by `0` returns `date`
+    /// unchanged even if it is not a business day.
+    fn advance_business_days(&self, date: Date, n: i32) -> Date;
+
+    /// Counts the number of business days in the half-open interval
+    /// `[d0, d1)`, i.e. including `d0` but excluding `d1`.
+    ///
+    /// If `d0` is after `d1`, the count is negative, mirroring the size
+    /// of the (now reversed) interval.
+    fn business_days_between(&self, d0: Date, d1: Date) -> i32;
+}
+
+impl BusinessDay for Region {
+    fn is_business_day(&self, date: Date) -> bool {
+        !matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday) && !self.is_holiday(date)
+    }
+
+    fn next_business_day(&self, date: Date, adjust_next: bool) -> Date {
+        let step = if adjust_next {
+            Duration::days(1)
+        } else {
+            Duration::days(-1)
+        };
+
+        let mut current = date;
+        while !self.is_business_day(current) {
+            current += step;
+        }
+        current
+    }
+
+    fn advance_business_days(&self, date: Date, n: i32) -> Date {
+        let step = if n >= 0 {
+            Duration::days(1)
+        } else {
+            Duration::days(-1)
+        };
+
+        let mut current = date;
+        let mut remaining = n.unsigned_abs();
+        while remaining > 0 {
+            current += step;
+            if self.is_business_day(current) {
+                remaining -= 1;
+            }
+        }
+        current
+    }
+
+    fn business_days_between(&self, d0: Date, d1: Date) -> i32 {
+        if d0 > d1 {
+            return -self.business_days_between(d1, d0);
+        }
+
+        let mut count = 0i32;
+        let mut current = d0;
+        while current < d1 {
+            if self.is_business_day(current) {
+                count += 1;
+            }
+            current += Duration::days(1);
+        }
+        count
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use time::Date;
 use time::Duration;
 use time::Month;
@@ -30,9 +32,124 @@ pub enum Holiday {
     BussUndBettag,
     ErsterWeihnachtsfeiertag,
     ZweiterWeihnachtsfeiertag,
+    Rosenmontag,
+    Aschermittwoch,
+    Gruendonnerstag,
+    Ostersonntag,
+    Heiligabend,
+    Silvester,
+    Volkstrauertag,
+    Totensonntag,
+    ErsterAdvent,
+    ZweiterAdvent,
+    DritterAdvent,
+    VierterAdvent,
+}
+
+/// Distinguishes legally mandated public holidays from informal
+/// observances that have no legal holiday status.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HolidayKind {
+    /// A legally mandated public holiday, as returned by [`crate::regions::Region::holidays`].
+    Public,
+    /// An informal observance with no legal holiday status (e.g. Heiligabend).
+    Observance,
 }
 
 impl Holiday {
+    /// Returns the canonical German name of the holiday.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Holiday::Neujahr => "Neujahr",
+            Holiday::HeiligeDreiKoenige => "Heilige Drei Könige",
+            Holiday::Frauentag => "Frauentag",
+            Holiday::Karfreitag => "Karfreitag",
+            Holiday::Ostermontag => "Ostermontag",
+            Holiday::TagDerArbeit => "Tag der Arbeit",
+            Holiday::TagDerBefreiung => "Tag der Befreiung",
+            Holiday::ChristiHimmelfahrt => "Christi Himmelfahrt",
+            Holiday::Pfingstsonntag => "Pfingstsonntag",
+            Holiday::Pfingstmontag => "Pfingstmontag",
+            Holiday::Fronleichnam => "Fronleichnam",
+            Holiday::AugsburgerFriedensfest => "Augsburger Friedensfest",
+            Holiday::MariaeHimmelfahrt => "Mariä Himmelfahrt",
+            Holiday::Weltkindertag => "Weltkindertag",
+            Holiday::TagDerDeutschenEinheit => "Tag der Deutschen Einheit",
+            Holiday::Reformationstag => "Reformationstag",
+            Holiday::Allerheiligen => "Allerheiligen",
+            Holiday::BussUndBettag => "Buß- und Bettag",
+            Holiday::ErsterWeihnachtsfeiertag => "1. Weihnachtsfeiertag",
+            Holiday::ZweiterWeihnachtsfeiertag => "2. Weihnachtsfeiertag",
+            Holiday::Rosenmontag => "Rosenmontag",
+            Holiday::Aschermittwoch => "Aschermittwoch",
+            Holiday::Gruendonnerstag => "Gründonnerstag",
+            Holiday::Ostersonntag => "Ostersonntag",
+            Holiday::Heiligabend => "Heiligabend",
+            Holiday::Silvester => "Silvester",
+            Holiday::Volkstrauertag => "Volkstrauertag",
+            Holiday::Totensonntag => "Totensonntag",
+            Holiday::ErsterAdvent => "1. Advent",
+            Holiday::ZweiterAdvent => "2. Advent",
+            Holiday::DritterAdvent => "3. Advent",
+            Holiday::VierterAdvent => "4. Advent",
+        }
+    }
+
+    /// Returns whether the holiday is a legally mandated public holiday or
+    /// merely an informal observance.
+    ///
+    /// [`crate::regions::Region::holidays`] only ever returns [`Holiday`]s
+    /// of kind [`HolidayKind::Public`].
+    pub fn kind(&self) -> HolidayKind {
+        match self {
+            Holiday::Neujahr
+            | Holiday::HeiligeDreiKoenige
+            | Holiday::Frauentag
+            | Holiday::Karfreitag
+            | Holiday::Ostermontag
+            | Holiday::TagDerArbeit
+            | Holiday::TagDerBefreiung
+            | Holiday::ChristiHimmelfahrt
+            | Holiday::Pfingstmontag
+            | Holiday::Fronleichnam
+            | Holiday::AugsburgerFriedensfest
+            | Holiday::MariaeHimmelfahrt
+            | Holiday::Weltkindertag
+            | Holiday::TagDerDeutschenEinheit
+            | Holiday::Reformationstag
+            | Holiday::Allerheiligen
+            | Holiday::BussUndBettag
+            | Holiday::ErsterWeihnachtsfeiertag
+            | Holiday::ZweiterWeihnachtsfeiertag => HolidayKind::Public,
+            Holiday::Pfingstsonntag
+            | Holiday::Rosenmontag
+            | Holiday::Aschermittwoch
+            | Holiday::Gruendonnerstag
+            | Holiday::Ostersonntag
+            | Holiday::Heiligabend
+            | Holiday::Silvester
+            | Holiday::Volkstrauertag
+            | Holiday::Totensonntag
+            | Holiday::ErsterAdvent
+            | Holiday::ZweiterAdvent
+            | Holiday::DritterAdvent
+            | Holiday::VierterAdvent => HolidayKind::Observance,
+        }
+    }
+
+    /// Returns the range of years in which the holiday legally existed.
+    ///
+    /// Most holidays have always existed and always will, and thus return
+    /// the full `i32` range. Holidays that were introduced or abolished at
+    /// a specific point in history return a narrower range; [`Holiday::date`]
+    /// returns `None` for years outside of it.
+    pub fn valid_years(&self) -> RangeInclusive<i32> {
+        match self {
+            Holiday::TagDerDeutschenEinheit => 1954..=i32::MAX,
+            _ => i32::MIN..=i32::MAX,
+        }
+    }
+
     /// Returns the date of the holiday for a given year, if applicable.
     ///
     /// This method calculates the specific `Date` that corresponds to the holiday
@@ -48,6 +165,10 @@ impl Holiday {
     ///   - `Some(Date)`: If the holiday occurs in the specified year.
     ///   - `None`: If the calculation does not succeed, or the holiday is not defined for that year.
     pub fn date(&self, year: i32) -> Option<Date> {
+        if !self.valid_years().contains(&year) {
+            return None;
+        }
+
         match self {
             Holiday::Neujahr => Date::from_calendar_date(year, Month::January, 1).ok(),
             Holiday::HeiligeDreiKoenige => Date::from_calendar_date(year, Month::January, 6).ok(),
@@ -65,6 +186,9 @@ impl Holiday {
             }
             Holiday::MariaeHimmelfahrt => Date::from_calendar_date(year, Month::August, 15).ok(),
             Holiday::Weltkindertag => Date::from_calendar_date(year, Month::September, 20).ok(),
+            Holiday::TagDerDeutschenEinheit if year < 1990 => {
+                Date::from_calendar_date(year, Month::June, 17).ok()
+            }
             Holiday::TagDerDeutschenEinheit => {
                 Date::from_calendar_date(year, Month::October, 3).ok()
             }
@@ -77,6 +201,18 @@ impl Holiday {
             Holiday::ZweiterWeihnachtsfeiertag => {
                 Date::from_calendar_date(year, Month::December, 26).ok()
             }
+            Holiday::Rosenmontag => relative_to_easter_sunday(year, -48),
+            Holiday::Aschermittwoch => relative_to_easter_sunday(year, -46),
+            Holiday::Gruendonnerstag => relative_to_easter_sunday(year, -3),
+            Holiday::Ostersonntag => relative_to_easter_sunday(year, 0),
+            Holiday::Heiligabend => Date::from_calendar_date(year, Month::December, 24).ok(),
+            Holiday::Silvester => Date::from_calendar_date(year, Month::December, 31).ok(),
+            Holiday::Volkstrauertag => Some(vierter_advent(year)? - Duration::weeks(5)),
+            Holiday::Totensonntag => Some(vierter_advent(year)? - Duration::weeks(4)),
+            Holiday::ErsterAdvent => Some(vierter_advent(year)? - Duration::weeks(3)),
+            Holiday::ZweiterAdvent => Some(vierter_advent(year)? - Duration::weeks(2)),
+            Holiday::DritterAdvent => Some(vierter_advent(year)? - Duration::weeks(1)),
+            Holiday::VierterAdvent => vierter_advent(year),
         }
     }
 }
@@ -93,6 +229,13 @@ fn bus_und_bettag(year: i32) -> Option<Date> {
     Some(reference_date + duration_to_previous_wednesday)
 }
 
+/// Calculate the 4th Advent, i.e. the Sunday on or before 24 December
+fn vierter_advent(year: i32) -> Option<Date> {
+    let heiligabend = Date::from_calendar_date(year, Month::December, 24).ok()?;
+    let days_since_sunday = i64::from(heiligabend.weekday().number_days_from_sunday());
+    Some(heiligabend - Duration::days(days_since_sunday))
+}
+
 /// Calculate a date relative to east sunday
 fn relative_to_easter_sunday(year: i32, days_offset: i64) -> Option<Date> {
     let easter_sunday = computus_gregorian(year)?;
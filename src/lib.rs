@@ -28,9 +28,11 @@
 
 #![warn(missing_docs)]
 
+pub(crate) mod business_days;
 pub(crate) mod holidays;
 pub(crate) mod regions;
 
+pub use crate::business_days::*;
 pub use crate::holidays::*;
 pub use crate::regions::*;
 
@@ -68,10 +70,39 @@ pub trait HolidayExt {
     /// assert!(date.is_holiday(Region::Bayern));
     /// ```
     fn is_holiday(&self, region: Region) -> bool;
+
+    /// Checks if the given day is a holiday in any of the specified regions.
+    ///
+    /// This treats the given regions as a single combined calendar, useful
+    /// for organizations operating across several Bundesländer that must
+    /// observe the superset of their holidays.
+    ///
+    /// # Parameters
+    /// - `regions`: The set of regions to check for holidays.
+    ///
+    /// # Returns
+    /// - `true` if the current day is a holiday in at least one of the specified regions.
+    /// - `false` if the current day is not a holiday in any of the specified regions.
+    ///
+    /// # Example
+    /// ```
+    /// use feiertage::HolidayExt;
+    /// use feiertage::Region;
+    ///
+    /// let date = time::Date::from_calendar_date(2023, time::Month::January, 6).unwrap();
+    ///
+    /// // Heilige Drei Könige is not observed in Berlin, but is in Bayern
+    /// assert!(date.is_holiday_in_any(&[Region::Berlin, Region::Bayern]));
+    /// ```
+    fn is_holiday_in_any(&self, regions: &[Region]) -> bool;
 }
 
 impl HolidayExt for time::Date {
     fn is_holiday(&self, region: Region) -> bool {
         region.is_holiday(*self)
     }
+
+    fn is_holiday_in_any(&self, regions: &[Region]) -> bool {
+        regions.iter().any(|region| region.is_holiday(*self))
+    }
 }
@@ -1,4 +1,5 @@
 use crate::holidays::Holiday;
+use crate::holidays::HolidayKind;
 
 /// The `Region` enum represents the different regions (mostly German states) for which holidays
 /// can be calculated. It includes variations for regions where religious or minor
@@ -116,8 +117,8 @@ impl Region {
     /// - `true` if the given `date` is a holiday, based on the list of holidays in the region
     /// - `false` otherwise.
     pub fn is_holiday(&self, date: time::Date) -> bool {
-        let holidays = self.holidays();
         let year = date.year();
+        let holidays = self.holidays_in(year);
 
         for holiday in holidays {
             if let Some(holiday) = holiday.date(year) {
@@ -130,7 +131,85 @@ impl Region {
         false
     }
 
+    /// Returns the holiday that falls on the given date in this region, if any.
+    ///
+    /// Unlike [`Region::is_holiday`], this returns *which* holiday it is
+    /// rather than just whether one occurs.
+    pub fn holiday_on(&self, date: time::Date) -> Option<Holiday> {
+        let year = date.year();
+
+        self.holidays_in(year)
+            .into_iter()
+            .find(|holiday| holiday.date(year) == Some(date))
+    }
+
+    /// Returns every holiday in this region falling within the inclusive
+    /// range `start..=end`, sorted ascending by date.
+    ///
+    /// If a region lists a holiday that coincides with a nationwide one
+    /// (i.e. both fall on the same date), only one entry is kept for
+    /// that date.
+    pub fn holidays_between(
+        &self,
+        start: time::Date,
+        end: time::Date,
+    ) -> Vec<(time::Date, Holiday)> {
+        let mut result: Vec<(time::Date, Holiday)> = (start.year()..=end.year())
+            .flat_map(|year| {
+                self.holidays_in(year)
+                    .into_iter()
+                    .filter_map(move |holiday| holiday.date(year).map(|date| (date, holiday)))
+            })
+            .filter(|(date, _)| *date >= start && *date <= end)
+            .collect();
+
+        result.sort_by_key(|(date, _)| *date);
+        result.dedup_by_key(|(date, _)| *date);
+
+        result
+    }
+
+    /// Returns this region's holidays as observed in the given `year`.
+    ///
+    /// This exists because [`Holiday::BussUndBettag`] was a nationwide
+    /// public holiday until its 1995 abolition, after which it remained
+    /// a holiday only in the Sachsen variants. [`Region::holidays`] alone
+    /// cannot express this, since it has no year to key off of.
+    fn holidays_in(&self, year: i32) -> Vec<Holiday> {
+        let mut holidays = self.holidays();
+
+        if year <= 1994 && !holidays.contains(&Holiday::BussUndBettag) {
+            holidays.push(Holiday::BussUndBettag);
+        }
+
+        holidays
+    }
+
+    /// Merges and de-duplicates the holidays observed across several
+    /// regions, e.g. for employers who must observe the superset of
+    /// holidays of all the regions they operate in.
+    pub fn holidays_union(regions: &[Region]) -> Vec<Holiday> {
+        let mut holidays: Vec<Holiday> = Vec::new();
+
+        for region in regions {
+            for holiday in region.holidays() {
+                if !holidays.contains(&holiday) {
+                    holidays.push(holiday);
+                }
+            }
+        }
+
+        holidays
+    }
+
     /// Retrieves a list of holidays specific to a given region, including nationwide holidays.
+    ///
+    /// This reflects the present-day holiday calendar. In particular,
+    /// [`Holiday::BussUndBettag`] is only listed for the Sachsen variants,
+    /// since it was abolished nationwide in 1995; for historical queries,
+    /// [`Region::is_holiday`], [`Region::holiday_on`] and
+    /// [`Region::holidays_between`] additionally account for it having
+    /// been a nationwide holiday in every region up to and including 1994.
     pub fn holidays(&self) -> Vec<Holiday> {
         let mut holidays = match self {
             Region::BadenWuerttemberg => Vec::from([
@@ -201,6 +280,7 @@ impl Region {
         };
 
         holidays.extend_from_slice(NATION_WIDE_HOLIDAYS);
+        holidays.retain(|holiday| holiday.kind() == HolidayKind::Public);
 
         holidays
     }